@@ -1,4 +1,4 @@
-use crate::{models::Bonus, Client, Result, Stream, NO_QUERY};
+use crate::{models::Bonus, page::Page, Client, Result, Stream, NO_QUERY};
 use serde::Serialize;
 
 /// Get all bonuses for a given user as an automatically paged Stream.
@@ -20,6 +20,25 @@ where
     client.get_stream(&format!("/users/{}/bonuses", user_id), page_size, params)
 }
 
+/// Get a single page of bonuses at the given `skip`/`limit` offset.
+///
+/// Unlike [`for_user`], this returns a [`Page`] the caller can walk forwards
+/// and backwards with [`Page::next`]/[`Page::prev`].
+///
+/// See: [List
+/// Bonuses](https://bonusly.docs.apiary.io/#reference/0/bonuses/list-bonuses)
+pub async fn page<Q>(
+    client: &Client,
+    skip: usize,
+    limit: usize,
+    params: &Q,
+) -> Result<Page<Bonus>>
+where
+    Q: Serialize + ?Sized,
+{
+    Page::fetch(client, "/bonuses", params, skip, limit).await
+}
+
 /// Get a bonus by its id
 ///
 /// See: [Retrieve a
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use futures::{future, stream, Future as StdFuture, FutureExt, Stream as StdStream, TryFutureExt};
+use futures::{stream, Future as StdFuture, FutureExt, Stream as StdStream, TryFutureExt};
+use rand::Rng;
 use reqwest::{self, header, Method};
 use serde::{de::DeserializeOwned, ser::Serialize, Deserialize};
 use std::{env, pin::Pin, time::Duration};
@@ -9,7 +10,9 @@ pub use result::{Error, Result};
 
 pub mod bonuses;
 pub mod models;
+pub mod page;
 pub mod users;
+pub mod values;
 pub mod webhooks;
 
 /// A type alias for `Future` that may return `crate::error::Error`
@@ -23,6 +26,36 @@ pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 pub const BASE_URL: &str = "https://bonus.ly/api/v1";
 pub const PAGE_SIZE: usize = 20;
 
+/// The default number of times a retryable request is attempted before giving
+/// up.
+pub const RETRY_MAX_ATTEMPTS: u32 = 3;
+/// The default base delay for exponential backoff between retries.
+pub const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// The default ceiling for a single backoff delay.
+pub const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Controls how the [`Client`] retries rate-limited (`429`) and temporarily
+/// unavailable (`503`) responses.
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// The maximum number of attempts, including the initial one.
+    pub max_attempts: u32,
+    /// The base delay used for exponential backoff.
+    pub base_delay: Duration,
+    /// The ceiling applied to any single backoff delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: RETRY_MAX_ATTEMPTS,
+            base_delay: RETRY_BASE_DELAY,
+            max_delay: RETRY_MAX_DELAY,
+        }
+    }
+}
+
 /// A utility constant to pass an empty query slice to the various client fetch
 /// functions
 pub const NO_QUERY: &[&str; 0] = &[""; 0];
@@ -49,6 +82,7 @@ impl<T> From<Response<T>> for Result<T> {
 pub struct Client {
     base_url: String,
     client: reqwest::Client,
+    retry: RetryConfig,
 }
 
 impl Default for Client {
@@ -68,23 +102,18 @@ impl Client {
         Ok(Self::new(&token_from_env()?))
     }
 
+    /// Start building a client for the given access token.
+    ///
+    /// Use the returned [`ClientBuilder`] to override the base URL, request
+    /// timeout, proxy, or user agent before calling
+    /// [`build`](ClientBuilder::build).
+    pub fn builder(token: &str) -> ClientBuilder {
+        ClientBuilder::new(token)
+    }
+
     /// Create a new bonus.ly client using a given access token
     pub fn new(token: &str) -> Self {
-        let mut headers = header::HeaderMap::new();
-        let mut token_value = header::HeaderValue::from_str(&format!("Bearer {}", token))
-            .expect("valid bearer token");
-        token_value.set_sensitive(true);
-        headers.insert(header::AUTHORIZATION, token_value);
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .gzip(true)
-            .timeout(REQUEST_TIMEOUT)
-            .build()
-            .expect("reqwest client");
-        Self {
-            base_url: BASE_URL.to_owned(),
-            client,
-        }
+        Self::builder(token).build().expect("reqwest client")
     }
 
     fn _get<T, Q, V>(&self, path: &str, query: &Q, add_query: &V) -> Future<T>
@@ -94,24 +123,106 @@ impl Client {
         V: Serialize + ?Sized,
     {
         let request_url = format!("{}{}", self.base_url, path);
-        self.client
-            .get(&request_url)
-            .query(query)
-            .query(add_query)
-            .send()
-            .map_err(Error::from)
-            .and_then(|result| match result.error_for_status() {
-                Ok(result) => {
-                    let fut: Future<T> = result
-                        .json::<Response<T>>()
-                        .map_err(Error::from)
-                        .and_then(|response| async { Result::from(response) })
-                        .boxed();
-                    fut
+        let builder = self.client.get(&request_url).query(query).query(add_query);
+        self.run(Method::GET, builder, path)
+    }
+
+    /// Send a prepared request, parse its [`Response`] envelope, and (when the
+    /// `tracing` feature is enabled) record a span with the method, path, final
+    /// status, and elapsed time, emitting a `warn`/`error` event when the HTTP
+    /// status or the API envelope indicates failure.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn run<R>(&self, method: Method, builder: reqwest::RequestBuilder, path: &str) -> Future<R>
+    where
+        R: 'static + DeserializeOwned + std::marker::Send,
+    {
+        let client = self.clone();
+        let path = path.to_string();
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("request", method = %method, path = %path);
+        let request = async move {
+            #[cfg(feature = "tracing")]
+            let start = std::time::Instant::now();
+            let response = client.send(builder).await;
+            #[cfg(feature = "tracing")]
+            if let Err(err) = &response {
+                tracing::error!(
+                    method = %method,
+                    path = %path,
+                    error = %err,
+                    elapsed_ms = start.elapsed().as_millis() as u64,
+                    "request failed"
+                );
+            }
+            let response = response?;
+            let status = response.status();
+            match response.error_for_status() {
+                Ok(response) => {
+                    let parsed = response.json::<Response<R>>().await?;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        method = %method,
+                        path = %path,
+                        status = %status,
+                        elapsed_ms = start.elapsed().as_millis() as u64,
+                        "request complete"
+                    );
+                    #[cfg(feature = "tracing")]
+                    if !parsed.success {
+                        tracing::warn!(
+                            method = %method,
+                            path = %path,
+                            message = parsed.message.as_deref().unwrap_or_default(),
+                            "api responded with success=false"
+                        );
+                    }
+                    Result::from(parsed)
                 }
-                Err(e) => future::err(Error::from(e)).boxed(),
-            })
-            .boxed()
+                Err(err) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(
+                        method = %method,
+                        path = %path,
+                        status = %status,
+                        elapsed_ms = start.elapsed().as_millis() as u64,
+                        "request failed"
+                    );
+                    Err(Error::from(err))
+                }
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(request, span);
+        request.boxed()
+    }
+
+    /// Issue a request, transparently retrying `429` and `503` responses
+    /// according to the client's [`RetryConfig`].
+    ///
+    /// A `Retry-After` header is honored when present, otherwise successive
+    /// attempts back off exponentially with full jitter. Once the attempt
+    /// budget is exhausted an [`Error::RetriesExhausted`] is returned.
+    async fn send(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let request = builder
+                .try_clone()
+                .ok_or_else(|| Error::custom("request body cannot be retried"))?;
+            let response = request.send().await?;
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+            {
+                attempt += 1;
+                if attempt >= self.retry.max_attempts {
+                    return Err(Error::retries_exhausted(status, attempt));
+                }
+                let delay = retry_after(&response).unwrap_or_else(|| backoff(&self.retry, attempt));
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+            return Ok(response);
+        }
     }
 
     pub(crate) fn get<T, Q>(&self, path: &str, query: &Q) -> Future<T>
@@ -122,6 +233,22 @@ impl Client {
         self._get(path, query, NO_QUERY)
     }
 
+    /// Fetch a single page of results at the given `skip`/`limit` offset,
+    /// using the same query mechanics as [`get_stream`](Self::get_stream).
+    pub(crate) fn get_page<T, Q>(
+        &self,
+        path: &str,
+        query: &Q,
+        skip: usize,
+        limit: usize,
+    ) -> Future<Vec<T>>
+    where
+        T: 'static + DeserializeOwned + std::marker::Send,
+        Q: Serialize + ?Sized,
+    {
+        self._get(path, query, &[("skip", skip), ("limit", limit)])
+    }
+
     pub(crate) fn get_stream<E, Q>(&self, path: &str, limit: usize, query: &'static Q) -> Stream<E>
     where
         E: 'static + DeserializeOwned + std::marker::Send,
@@ -167,6 +294,9 @@ impl Client {
             .boxed()
     }
 
+    // Part of the verb surface shared with `post`; retained for symmetry even
+    // though no endpoint currently issues a PUT.
+    #[allow(dead_code)]
     pub(crate) fn put<T, R>(&self, path: &str, json: &T) -> Future<R>
     where
         T: Serialize + ?Sized,
@@ -189,23 +319,8 @@ impl Client {
         R: 'static + DeserializeOwned + std::marker::Send,
     {
         let request_url = format!("{}{}", self.base_url, path);
-        self.client
-            .request(method, &request_url)
-            .json(json)
-            .send()
-            .map_err(Error::from)
-            .and_then(|response| match response.error_for_status() {
-                Ok(result) => {
-                    let fut: Future<R> = result
-                        .json::<Response<R>>()
-                        .map_err(Error::from)
-                        .and_then(|response| async { Result::from(response) })
-                        .boxed();
-                    fut
-                }
-                Err(e) => future::err(Error::from(e)).boxed(),
-            })
-            .boxed()
+        let builder = self.client.request(method.clone(), &request_url).json(json);
+        self.run(method, builder, path)
     }
 
     pub(crate) fn delete<R>(&self, path: &str) -> Future<R>
@@ -213,22 +328,117 @@ impl Client {
         R: 'static + DeserializeOwned + std::marker::Send,
     {
         let request_url = format!("{}{}", self.base_url, path);
-        self.client
-            .delete(&request_url)
-            .send()
-            .map_err(Error::from)
-            .and_then(|response| match response.error_for_status() {
-                Ok(result) => {
-                    let fut: Future<R> = result
-                        .json::<Response<R>>()
-                        .map_err(Error::from)
-                        .and_then(|response| async { Result::from(response) })
-                        .boxed();
-                    fut
-                }
-                Err(e) => future::err(Error::from(e)).boxed(),
-            })
-            .boxed()
+        let builder = self.client.delete(&request_url);
+        self.run(Method::DELETE, builder, path)
+    }
+}
+
+/// The value of a `Retry-After` header expressed as a delay, if present and
+/// parseable as whole seconds.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after)
+}
+
+/// Parse a `Retry-After` header value expressed as whole seconds into a delay.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Compute the full-jitter exponential backoff delay for the given attempt.
+fn backoff(config: &RetryConfig, attempt: u32) -> Duration {
+    let exp = config
+        .base_delay
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+    let capped = exp.min(config.max_delay);
+    let millis = capped.as_millis() as u64;
+    Duration::from_millis(rand::thread_rng().gen_range(0..=millis))
+}
+
+/// A builder for a [`Client`] allowing the base URL, request timeout, proxy,
+/// and user agent to be customized before the underlying `reqwest::Client` is
+/// constructed.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    token: String,
+    base_url: String,
+    timeout: Duration,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    retry: RetryConfig,
+}
+
+impl ClientBuilder {
+    /// Create a new builder for the given access token, defaulting the base URL
+    /// and timeout to [`BASE_URL`] and [`REQUEST_TIMEOUT`].
+    pub fn new(token: &str) -> Self {
+        Self {
+            token: token.to_owned(),
+            base_url: BASE_URL.to_owned(),
+            timeout: REQUEST_TIMEOUT,
+            user_agent: None,
+            proxy: None,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Set the base URL requests are issued against, e.g. to point at a staging
+    /// host.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    /// Set the request timeout, overriding the default [`REQUEST_TIMEOUT`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Route requests through the given proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// Override how rate-limited and temporarily unavailable responses are
+    /// retried.
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Build the [`Client`], consuming the builder.
+    pub fn build(self) -> Result<Client> {
+        let mut headers = header::HeaderMap::new();
+        let mut token_value = header::HeaderValue::from_str(&format!("Bearer {}", self.token))
+            .map_err(|_| Error::custom("invalid bearer token"))?;
+        token_value.set_sensitive(true);
+        headers.insert(header::AUTHORIZATION, token_value);
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .gzip(true)
+            .timeout(self.timeout);
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        Ok(Client {
+            base_url: self.base_url,
+            client: builder.build()?,
+            retry: self.retry,
+        })
     }
 }
 
@@ -258,3 +468,31 @@ pub trait IntoVec: StreamExt {
         self.collect::<Vec<Result<T>>>().await.into_iter().collect()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{backoff, parse_retry_after, RetryConfig};
+    use std::time::Duration;
+
+    #[test]
+    fn retry_after_parsing() {
+        assert_eq!(parse_retry_after("5"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_retry_after(" 12 "), Some(Duration::from_secs(12)));
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[test]
+    fn backoff_respects_bounds() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+        };
+        // Full jitter never exceeds the configured ceiling, and the first
+        // attempt never exceeds the base delay.
+        for attempt in 1..=config.max_attempts {
+            assert!(backoff(&config, attempt) <= config.max_delay);
+        }
+        assert!(backoff(&config, 1) <= config.base_delay);
+    }
+}
@@ -1,4 +1,5 @@
 use super::User;
+use crate::values::Currency;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
@@ -11,7 +12,7 @@ pub struct Bonus {
     pub reason_decoded: String,
     pub reason_html: String,
     pub amount: u32,
-    pub amount_with_currency: String,
+    pub amount_with_currency: Currency,
     pub family_amount: u32,
     pub value: Option<String>,
     pub hashtag: Option<String>,
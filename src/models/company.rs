@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Company {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub custom_currency_name: String,
+    pub custom_currency_name_singular: String,
+    pub custom_currency_plural: Option<String>,
+    pub active_users_count: u64,
+}
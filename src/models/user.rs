@@ -1,3 +1,4 @@
+use crate::values::Currency;
 use chrono::{DateTime, Utc};
 use http::Uri;
 use serde::Deserialize;
@@ -39,8 +40,8 @@ pub struct User {
     pub custom_properties: serde_json::Map<String, serde_json::Value>,
     pub status: String,
     pub earning_balance: Option<u64>,
-    pub earning_balance_with_currency: Option<String>,
+    pub earning_balance_with_currency: Option<Currency>,
     pub lifetime_earnings: Option<u64>,
-    pub lifetime_earnings_with_currency: Option<String>,
+    pub lifetime_earnings_with_currency: Option<Currency>,
     pub admin: Option<bool>,
 }
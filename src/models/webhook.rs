@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// The kinds of events Bonus.ly can deliver to a registered webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EventType {
+    #[serde(rename = "bonus.created")]
+    BonusCreated,
+    #[serde(rename = "bonus.updated")]
+    BonusUpdated,
+    #[serde(rename = "bonus.deleted")]
+    BonusDeleted,
+    #[serde(rename = "redemption.created")]
+    RedemptionCreated,
+}
+
+/// A webhook registration as returned by the Bonus.ly API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Webhook {
+    pub id: Option<String>,
+    pub url: String,
+    pub event_types: Vec<EventType>,
+}
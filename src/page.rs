@@ -0,0 +1,138 @@
+use crate::{Client, Error, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A single, explicitly-addressed page of results.
+///
+/// Where [`get_stream`](crate::Client::get_stream) drains every entry, a `Page`
+/// gives callers building paginated UIs direct control over the `skip`/`limit`
+/// window and the ability to walk forwards and backwards with
+/// [`next`](Page::next) and [`prev`](Page::prev), mirroring elefren's `page`
+/// module.
+///
+/// Note: the Bonus.ly response envelope carries no total count, so there is no
+/// `total()` accessor. The offset is tracked via [`skip`](Page::skip) and
+/// [`limit`](Page::limit); the end of the collection is reached when a fetched
+/// page comes back with fewer than `limit` [`items`](Page::items) (an empty
+/// page past the end). Callers should stop paging on a short or empty page.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    client: Client,
+    path: String,
+    query: Vec<(String, String)>,
+    skip: usize,
+    limit: usize,
+    items: Vec<T>,
+}
+
+impl<T> Page<T>
+where
+    T: 'static + DeserializeOwned + std::marker::Send,
+{
+    /// Fetch the page at the given `skip`/`limit` offset for the given path and
+    /// query parameters.
+    pub(crate) async fn fetch<Q>(
+        client: &Client,
+        path: &str,
+        params: &Q,
+        skip: usize,
+        limit: usize,
+    ) -> Result<Self>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let encoded = serde_urlencoded::to_string(params)
+            .map_err(|err| Error::custom(err.to_string()))?;
+        let query: Vec<(String, String)> =
+            serde_urlencoded::from_str(&encoded).map_err(|err| Error::custom(err.to_string()))?;
+        Self::at(client.clone(), path.to_string(), query, skip, limit).await
+    }
+
+    async fn at(
+        client: Client,
+        path: String,
+        query: Vec<(String, String)>,
+        skip: usize,
+        limit: usize,
+    ) -> Result<Self> {
+        let items = client.get_page(&path, &query, skip, limit).await?;
+        Ok(Self {
+            client,
+            path,
+            query,
+            skip,
+            limit,
+            items,
+        })
+    }
+
+    /// The entries on this page.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consume the page, returning its entries.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// The `skip` offset this page was fetched at.
+    pub fn skip(&self) -> usize {
+        self.skip
+    }
+
+    /// The `limit` (page size) this page was fetched with.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Fetch the following page, advancing `skip` by `limit`.
+    pub async fn next(&self) -> Result<Self> {
+        Self::at(
+            self.client.clone(),
+            self.path.clone(),
+            self.query.clone(),
+            next_skip(self.skip, self.limit),
+            self.limit,
+        )
+        .await
+    }
+
+    /// Fetch the preceding page, clamping `skip` at zero.
+    pub async fn prev(&self) -> Result<Self> {
+        Self::at(
+            self.client.clone(),
+            self.path.clone(),
+            self.query.clone(),
+            prev_skip(self.skip, self.limit),
+            self.limit,
+        )
+        .await
+    }
+}
+
+/// The `skip` offset of the page following one at `skip` with the given
+/// `limit`.
+fn next_skip(skip: usize, limit: usize) -> usize {
+    skip + limit
+}
+
+/// The `skip` offset of the page preceding one at `skip` with the given
+/// `limit`, clamped at zero.
+fn prev_skip(skip: usize, limit: usize) -> usize {
+    skip.saturating_sub(limit)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{next_skip, prev_skip};
+
+    #[test]
+    fn page_offsets() {
+        assert_eq!(next_skip(0, 20), 20);
+        assert_eq!(next_skip(40, 20), 60);
+        assert_eq!(prev_skip(40, 20), 20);
+        // A first page cannot step back past zero.
+        assert_eq!(prev_skip(0, 20), 0);
+        assert_eq!(prev_skip(10, 20), 0);
+    }
+}
@@ -0,0 +1,38 @@
+use thiserror::Error;
+
+/// A convenient `Result` type alias for this crate.
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Represents all errors that can occur while talking to the Bonus.ly API.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// An error returned by the underlying `reqwest` transport.
+    #[error("request error: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    /// The API responded with `success == false` and a message.
+    #[error("api error: {0}")]
+    Api(String),
+    /// A retryable response kept failing until the retry budget was exhausted.
+    #[error("retries exhausted after {attempts} attempts (last status {status})")]
+    RetriesExhausted {
+        status: reqwest::StatusCode,
+        attempts: u32,
+    },
+    /// A catch-all for errors raised by this crate.
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl Error {
+    pub fn api_error(msg: impl Into<String>) -> Self {
+        Self::Api(msg.into())
+    }
+
+    pub fn custom(msg: impl Into<String>) -> Self {
+        Self::Custom(msg.into())
+    }
+
+    pub(crate) fn retries_exhausted(status: reqwest::StatusCode, attempts: u32) -> Self {
+        Self::RetriesExhausted { status, attempts }
+    }
+}
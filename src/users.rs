@@ -1,4 +1,4 @@
-use crate::{models::User, Client, Result, Stream, NO_QUERY};
+use crate::{models::User, page::Page, Client, Result, Stream, NO_QUERY};
 use serde::Serialize;
 
 /// Get all users as an automatically paged Stream.
@@ -15,6 +15,25 @@ where
     client.get_stream("/users", page_size, params)
 }
 
+/// Get a single page of users at the given `skip`/`limit` offset.
+///
+/// Unlike [`all`], this returns a [`Page`] the caller can walk forwards and
+/// backwards with [`Page::next`]/[`Page::prev`].
+///
+/// See: [List
+/// Users](https://bonusly.docs.apiary.io/#reference/0/users/list-users)
+pub async fn page<Q>(
+    client: &Client,
+    skip: usize,
+    limit: usize,
+    params: &Q,
+) -> Result<Page<User>>
+where
+    Q: Serialize + ?Sized,
+{
+    Page::fetch(client, "/users", params, skip, limit).await
+}
+
 /// Get a specific user by their id
 ///
 /// See: [Retrieve a
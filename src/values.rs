@@ -0,0 +1,241 @@
+use crate::{Error, Result};
+use serde::{de, Deserialize, Deserializer};
+use std::{
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// A currency amount as returned by the Bonus.ly API in the various
+/// `*_with_currency` fields.
+///
+/// The amount is kept as an integer number of minor units at the precision it
+/// was written with (`$12.00` is 1200 with two decimals, `$5` is 5 with none,
+/// `50 points` is 50) together with the symbol or
+/// ISO-4217 code it was expressed in, so callers can sum and compare balances
+/// without re-parsing strings. This mirrors the typed `Dbi`/`Hnt`/`Usd` values
+/// exposed by helium-api.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Currency {
+    amount: i64,
+    decimals: u32,
+    code: String,
+}
+
+/// The maximum number of fractional digits honored when parsing; amounts with
+/// more are rejected so the `10^decimals` scale factor always fits in an `i64`.
+const MAX_DECIMALS: u32 = 9;
+
+impl Currency {
+    /// Create a currency value from a whole number of minor units and a symbol
+    /// or ISO-4217 code.
+    ///
+    /// `decimals` is clamped to [`MAX_DECIMALS`] so later scaling can't panic.
+    pub fn new(amount: i64, decimals: u32, code: impl Into<String>) -> Self {
+        Self {
+            amount,
+            decimals: decimals.min(MAX_DECIMALS),
+            code: code.into(),
+        }
+    }
+
+    /// The amount expressed in units of `10^-decimals` (cents for `$12.00`,
+    /// points for `50 points`).
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// The number of fractional digits the amount was expressed with.
+    pub fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// The symbol or ISO-4217 code the amount was expressed in.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+/// Whether `code` is a leading currency symbol that should be rendered as a
+/// prefix (`$12.00`) rather than a trailing unit (`50 points`).
+fn is_symbol(code: &str) -> bool {
+    matches!(code, "$" | "€" | "£" | "¥")
+}
+
+impl FromStr for Currency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        // Strip an optional leading sign first so both "+20 points" and
+        // "-$1.00" are handled uniformly regardless of symbol vs unit form.
+        let (sign, rest) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest.trim_start()),
+            None => match s.strip_prefix('+') {
+                Some(rest) => (1, rest.trim_start()),
+                None => (1, s),
+            },
+        };
+        let (amount, decimals, code) = match rest.chars().next() {
+            // Symbol-prefixed amounts such as "$12.00".
+            Some(first) if !first.is_ascii_digit() => {
+                let symbol: String = rest.chars().take_while(|c| !c.is_ascii_digit()).collect();
+                let (amount, decimals) = parse_amount(rest[symbol.len()..].trim())?;
+                (amount, decimals, symbol.trim().to_string())
+            }
+            // Trailing-unit amounts such as "50 points".
+            _ => {
+                let mut parts = rest.splitn(2, char::is_whitespace);
+                let number = parts.next().unwrap_or("").trim();
+                let code = parts.next().unwrap_or("").trim();
+                let (amount, decimals) = parse_amount(number)?;
+                (amount, decimals, code.to_string())
+            }
+        };
+        Ok(Self::new(sign * amount, decimals, code))
+    }
+}
+
+/// Parse a bare, unsigned decimal number into its minor-unit amount and the
+/// number of fractional digits seen.
+fn parse_amount(s: &str) -> Result<(i64, u32)> {
+    let s = s.replace(',', "");
+    let invalid = || Error::custom(format!("invalid currency amount: {}", s));
+    match s.split_once('.') {
+        Some((whole, frac)) => {
+            let decimals = frac.len() as u32;
+            if decimals > MAX_DECIMALS {
+                return Err(invalid());
+            }
+            let scale = 10i64.pow(decimals);
+            let whole: i64 = whole.parse().map_err(|_| invalid())?;
+            let frac: i64 = frac.parse().map_err(|_| invalid())?;
+            whole
+                .checked_mul(scale)
+                .and_then(|w| w.checked_add(frac))
+                .map(|amount| (amount, decimals))
+                .ok_or_else(invalid)
+        }
+        None => Ok((s.parse().map_err(|_| invalid())?, 0)),
+    }
+}
+
+impl Currency {
+    /// Re-express the amount using a (larger or equal) number of fractional
+    /// digits, so two values can be combined on a common scale.
+    fn rescale(&self, decimals: u32) -> i64 {
+        self.amount.saturating_mul(10i64.pow(decimals - self.decimals))
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = 10i64.pow(self.decimals);
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let magnitude = self.amount.abs();
+        let number = if self.decimals == 0 {
+            (magnitude / scale).to_string()
+        } else {
+            format!(
+                "{}.{:0width$}",
+                magnitude / scale,
+                magnitude % scale,
+                width = self.decimals as usize
+            )
+        };
+        let body = if is_symbol(&self.code) {
+            format!("{}{}", self.code, number)
+        } else if self.code.is_empty() {
+            number
+        } else {
+            format!("{} {}", number, self.code)
+        };
+        write!(f, "{}{}", sign, body)
+    }
+}
+
+impl Add for Currency {
+    type Output = Currency;
+
+    fn add(self, rhs: Currency) -> Currency {
+        assert_eq!(self.code, rhs.code, "cannot add mismatched currencies");
+        let decimals = self.decimals.max(rhs.decimals);
+        Currency::new(self.rescale(decimals) + rhs.rescale(decimals), decimals, self.code)
+    }
+}
+
+impl Sub for Currency {
+    type Output = Currency;
+
+    fn sub(self, rhs: Currency) -> Currency {
+        assert_eq!(self.code, rhs.code, "cannot subtract mismatched currencies");
+        let decimals = self.decimals.max(rhs.decimals);
+        Currency::new(self.rescale(decimals) - rhs.rescale(decimals), decimals, self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Currency;
+
+    #[test]
+    fn parse_points() {
+        let points: Currency = "50 points".parse().expect("points");
+        assert_eq!(points.amount(), 50);
+        assert_eq!(points.code(), "points");
+        assert_eq!(points.to_string(), "50 points");
+    }
+
+    #[test]
+    fn parse_signed_points() {
+        // Bonus amounts arrive signed, e.g. "+20 points".
+        let plus: Currency = "+20 points".parse().expect("plus");
+        assert_eq!(plus.amount(), 20);
+        assert_eq!(plus.to_string(), "20 points");
+
+        let minus: Currency = "-20 points".parse().expect("minus");
+        assert_eq!(minus.amount(), -20);
+        assert_eq!(minus.to_string(), "-20 points");
+    }
+
+    #[test]
+    fn parse_money() {
+        let usd: Currency = "$12.00".parse().expect("usd");
+        assert_eq!(usd.amount(), 1200);
+        assert_eq!(usd.code(), "$");
+        assert_eq!(usd.to_string(), "$12.00");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a: Currency = "$12.00".parse().expect("a");
+        let b: Currency = "$1.50".parse().expect("b");
+        assert_eq!((a - b).to_string(), "$10.50");
+    }
+
+    #[test]
+    fn arithmetic_mixed_scale() {
+        // Differing scales are normalized before combining.
+        let a: Currency = "$12.00".parse().expect("a");
+        let b: Currency = "$5".parse().expect("b");
+        assert_eq!((a + b).to_string(), "$17.00");
+    }
+
+    #[test]
+    fn arithmetic_negative() {
+        // A negative result keeps its sign, even below one major unit.
+        let a: Currency = "$1.00".parse().expect("a");
+        let b: Currency = "$1.50".parse().expect("b");
+        assert_eq!((a - b).to_string(), "-$0.50");
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    models::{Bonus, EventType, User},
+    Error, Result,
+};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A strongly-typed webhook event, keyed on its [`EventType`], carrying the
+/// payload Bonus.ly POSTs back to a registered callback.
+///
+/// Parse an incoming request body with [`WebhookEvent::from_slice`] after
+/// authenticating it with [`verify_signature`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "event", content = "data")]
+pub enum WebhookEvent {
+    #[serde(rename = "bonus.created")]
+    BonusCreated(Bonus),
+    #[serde(rename = "bonus.updated")]
+    BonusUpdated(Bonus),
+    #[serde(rename = "bonus.deleted")]
+    BonusDeleted(Bonus),
+    #[serde(rename = "redemption.created")]
+    RedemptionCreated(User),
+}
+
+impl WebhookEvent {
+    /// Deserialize a raw webhook request body into a typed event.
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        serde_json::from_slice(body).map_err(|err| Error::custom(err.to_string()))
+    }
+
+    /// The [`EventType`] this event corresponds to.
+    pub fn event_type(&self) -> EventType {
+        match self {
+            WebhookEvent::BonusCreated(_) => EventType::BonusCreated,
+            WebhookEvent::BonusUpdated(_) => EventType::BonusUpdated,
+            WebhookEvent::BonusDeleted(_) => EventType::BonusDeleted,
+            WebhookEvent::RedemptionCreated(_) => EventType::RedemptionCreated,
+        }
+    }
+}
+
+/// Verify the HMAC-SHA256 signature attached to a webhook callback.
+///
+/// The digest is recomputed over `raw_body` using `secret` and compared in
+/// constant time against the hex-encoded `header_value` from the signature
+/// header, letting servers authenticate the callback before parsing it.
+///
+/// `header_value` may be either a bare hex digest or one prefixed with
+/// `sha256=`; the prefix is stripped before decoding.
+pub fn verify_signature(secret: &[u8], raw_body: &[u8], header_value: &str) -> Result<bool> {
+    let mut mac =
+        HmacSha256::new_from_slice(secret).map_err(|_| Error::custom("invalid hmac key length"))?;
+    mac.update(raw_body);
+    let digest = header_value
+        .trim()
+        .strip_prefix("sha256=")
+        .unwrap_or_else(|| header_value.trim());
+    let provided = hex::decode(digest).map_err(|_| Error::custom("invalid signature encoding"))?;
+    Ok(mac.verify_slice(&provided).is_ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::verify_signature;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    fn signature(secret: &[u8], body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("hmac key");
+        mac.update(body);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        let secret = b"s3cr3t";
+        let body = br#"{"event":"bonus.created"}"#;
+        let header = signature(secret, body);
+        assert!(verify_signature(secret, body, &header).expect("verify"));
+        assert!(verify_signature(secret, body, &format!("sha256={}", header)).expect("verify"));
+    }
+
+    #[test]
+    fn rejects_tampered_signature() {
+        let secret = b"s3cr3t";
+        let body = br#"{"event":"bonus.created"}"#;
+        let header = signature(secret, br#"{"event":"bonus.deleted"}"#);
+        assert!(!verify_signature(secret, body, &header).expect("verify"));
+    }
+}
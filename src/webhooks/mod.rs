@@ -0,0 +1,37 @@
+use crate::{models::Webhook, Client, Result, Stream};
+use serde::Serialize;
+
+pub mod events;
+
+/// List all registered webhooks as an automatically paged Stream.
+///
+/// Note, do not pass `limit` or `skip` parameters since they are used
+/// internally for paging.
+///
+/// See: [List
+/// Webhooks](https://bonusly.docs.apiary.io/#reference/0/webhooks/list-webhooks)
+pub fn all<Q>(client: &Client, page_size: usize, params: &'static Q) -> Stream<Webhook>
+where
+    Q: Serialize + ?Sized + std::marker::Sync,
+{
+    client.get_stream("/webhooks", page_size, params)
+}
+
+/// Register a new webhook.
+///
+/// See: [Create a
+/// Webhook](https://bonusly.docs.apiary.io/#reference/0/webhooks/create-a-webhook)
+pub async fn create<Q>(client: &Client, params: &'static Q) -> Result<Webhook>
+where
+    Q: Serialize + ?Sized + std::marker::Sync,
+{
+    client.post("/webhooks", params).await
+}
+
+/// Delete a webhook by its id.
+///
+/// See: [Delete a
+/// Webhook](https://bonusly.docs.apiary.io/#reference/0/webhooks/delete-a-webhook)
+pub async fn delete(client: &Client, id: &str) -> Result<()> {
+    client.delete(&format!("/webhooks/{}", id)).await
+}